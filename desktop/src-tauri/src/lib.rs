@@ -1,15 +1,62 @@
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 use tauri::{Emitter, Manager};
 use tauri_plugin_shell::ShellExt;
 use tauri_plugin_shell::process::CommandChild;
-use tokio::sync::Mutex;
+use tokio::net::TcpStream;
+use tokio::sync::{oneshot, Mutex};
+
+// Registry of cancellable background operations, keyed by a caller-supplied
+// id. `cancel_task` aborts the handle and, where one is tracked, kills the
+// associated child process.
+type TaskRegistry = Arc<Mutex<HashMap<String, tauri::async_runtime::JoinHandle<()>>>>;
+type ChildRegistry = Arc<Mutex<HashMap<String, CommandChild>>>;
+
+// Resolved once logging is initialized in `run()`'s setup hook, so
+// `get_log_path` can report it without threading state through every command.
+static LOG_PATH: OnceLock<PathBuf> = OnceLock::new();
+// Cap on-disk log growth before we roll the previous file aside.
+const MAX_LOG_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+// The severity actually enforced for every record. `fern::Dispatch` is built
+// with no static `.level()` cap (it defaults to `Trace`, letting everything
+// through to our `.filter()`), so this is the sole gate — unlike
+// `log::set_max_level`, lowering it here genuinely re-enables verbose output.
+static LOG_LEVEL: std::sync::Mutex<log::LevelFilter> = std::sync::Mutex::new(log::LevelFilter::Info);
+
+fn current_log_level() -> log::LevelFilter {
+    *LOG_LEVEL.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// How long start_backend will wait for the sidecar to accept connections
+// before giving up.
+const READINESS_TIMEOUT: Duration = Duration::from_secs(15);
+// Polling interval between readiness probes, doubled after each failed
+// attempt up to READINESS_POLL_MAX.
+const READINESS_POLL_MIN: Duration = Duration::from_millis(100);
+const READINESS_POLL_MAX: Duration = Duration::from_secs(2);
+
+// Supervisor backoff for automatic restarts after an unexpected crash.
+const MAX_RESTART_ATTEMPTS: u32 = 5;
+const RESTART_BACKOFF_BASE: Duration = Duration::from_secs(1);
+const RESTART_BACKOFF_MAX: Duration = Duration::from_secs(30);
 
 // Backend state management
 struct BackendState {
     child: Option<CommandChild>,
     port: u16,
     running: bool,
+    ready: bool,
+    // Set by `stop_backend` so the supervisor doesn't fight a deliberate shutdown.
+    stopping: bool,
+    restart_attempts: u32,
+    // The task id the current spawn attempt is registered under, so
+    // `cancel_task` can recognize a backend probe and reclaim its child.
+    task_id: Option<String>,
 }
 
 impl Default for BackendState {
@@ -18,6 +65,10 @@ impl Default for BackendState {
             child: None,
             port: 8000,
             running: false,
+            ready: false,
+            stopping: false,
+            restart_attempts: 0,
+            task_id: None,
         }
     }
 }
@@ -27,16 +78,237 @@ type SharedBackendState = Arc<Mutex<BackendState>>;
 #[derive(Serialize, Deserialize)]
 pub struct BackendStatus {
     running: bool,
+    ready: bool,
     port: u16,
 }
 
+#[derive(Clone, Serialize)]
+struct BackendRestartingPayload {
+    attempt: u32,
+    max_attempts: u32,
+}
+
+// A single log record forwarded to the frontend console.
+#[derive(Clone, Serialize)]
+struct LogPayload {
+    level: String,
+    target: String,
+    timestamp: String,
+    message: String,
+}
+
+// `log::Log` implementation that forwards every record to the frontend as an
+// `app-log` event, in addition to whatever fern has already written to disk.
+struct FrontendLogger {
+    app: tauri::AppHandle,
+}
+
+impl log::Log for FrontendLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let payload = LogPayload {
+            level: record.level().to_string(),
+            target: record.target().to_string(),
+            timestamp: chrono::Local::now().to_rfc3339(),
+            message: record.args().to_string(),
+        };
+        let _ = self.app.emit("app-log", payload);
+    }
+
+    fn flush(&self) {}
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct CLIStatus {
     installed: bool,
     path: Option<String>,
     version: Option<String>,
-    node_installed: bool,
-    npm_installed: bool,
+    node_path: Option<String>,
+    node_version: Option<String>,
+    npm_path: Option<String>,
+    npm_version: Option<String>,
+}
+
+// Initialize the logging subsystem: a rotating file under the app's log
+// directory, plus a `Log` implementation that mirrors every record to the
+// frontend as a structured `app-log` event.
+fn init_logging(app: &tauri::AppHandle) -> Result<(), String> {
+    let log_dir = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| format!("Failed to resolve log directory: {}", e))?;
+    std::fs::create_dir_all(&log_dir).map_err(|e| format!("Failed to create log directory: {}", e))?;
+
+    let log_path = log_dir.join("app.log");
+    let log_writer = RotatingLogWriter::open(log_path.clone())
+        .map_err(|e| format!("Failed to open log file: {}", e))?;
+
+    fern::Dispatch::new()
+        .format(|out, message, record| {
+            out.finish(format_args!(
+                "[{} {} {}] {}",
+                chrono::Local::now().to_rfc3339(),
+                record.level(),
+                record.target(),
+                message
+            ))
+        })
+        .filter(|metadata| metadata.level() <= current_log_level())
+        .chain(fern::Output::writer(
+            Box::new(log_writer) as Box<dyn std::io::Write + Send>,
+            "\n",
+        ))
+        .chain(Box::new(FrontendLogger { app: app.clone() }) as Box<dyn log::Log>)
+        .apply()
+        .map_err(|e| format!("Failed to initialize logger: {}", e))?;
+
+    LOG_PATH
+        .set(log_path)
+        .map_err(|_| "Logging was already initialized".to_string())?;
+    Ok(())
+}
+
+// `Write` target for fern that rolls `app.log` aside to `app.log.old` as soon
+// as it crosses `MAX_LOG_FILE_BYTES`, so a single long-running session still
+// gets rotation instead of only across restarts.
+struct RotatingLogWriter {
+    path: PathBuf,
+    file: std::fs::File,
+    written: u64,
+}
+
+impl RotatingLogWriter {
+    fn open(path: PathBuf) -> std::io::Result<Self> {
+        let file = std::fs::OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata()?.len();
+        Ok(Self { path, file, written })
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        let rotated = self.path.with_extension("log.old");
+        std::fs::rename(&self.path, &rotated)?;
+        self.file = std::fs::OpenOptions::new().create(true).append(true).open(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl std::io::Write for RotatingLogWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.written >= MAX_LOG_FILE_BYTES {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
+// Get the path to the current log file, so the UI can offer to attach it to
+// a bug report.
+#[tauri::command]
+fn get_log_path() -> Result<String, String> {
+    LOG_PATH
+        .get()
+        .map(|p| p.display().to_string())
+        .ok_or_else(|| "Logging has not been initialized".to_string())
+}
+
+// Change the minimum severity forwarded to the log file and the frontend
+// console. Updates the level fern's `.filter()` actually checks per record,
+// so this can loosen verbosity (e.g. to "debug") as well as tighten it —
+// `log::set_max_level` alone can only tighten, since fern would otherwise
+// still enforce its own fixed cutoff.
+#[tauri::command]
+fn set_log_level(level: String) -> Result<(), String> {
+    let level: log::LevelFilter = level
+        .parse()
+        .map_err(|_| format!("Invalid log level: {}", level))?;
+    *LOG_LEVEL.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = level;
+    Ok(())
+}
+
+// Recognize a leading Python `logging`-style level prefix (`INFO:`, `ERROR:`,
+// ...) so sidecar output keeps its original severity instead of collapsing
+// to one level. Falls back to `default` when no prefix is present.
+fn parse_sidecar_level(line: &str, default: log::Level) -> (log::Level, &str) {
+    const PREFIXES: &[(&str, log::Level)] = &[
+        ("CRITICAL:", log::Level::Error),
+        ("ERROR:", log::Level::Error),
+        ("WARNING:", log::Level::Warn),
+        ("WARN:", log::Level::Warn),
+        ("INFO:", log::Level::Info),
+        ("DEBUG:", log::Level::Debug),
+    ];
+
+    for (prefix, level) in PREFIXES {
+        if let Some(rest) = line.strip_prefix(prefix) {
+            return (*level, rest.trim());
+        }
+    }
+    (default, line)
+}
+
+// Cancel a background operation previously registered under `id` (backend
+// startup probing, a CLI install/update). Aborts its task and kills whatever
+// child process is tied to it — the install/update `npm` process from
+// `ChildRegistry`, or, if `id` matches the backend's current probe, the
+// backend sidecar itself, with `BackendState` reset so a later
+// `start_backend` call re-probes instead of short-circuiting on stale state.
+#[tauri::command]
+async fn cancel_task(
+    app: tauri::AppHandle,
+    backend: tauri::State<'_, SharedBackendState>,
+    tasks: tauri::State<'_, TaskRegistry>,
+    children: tauri::State<'_, ChildRegistry>,
+    id: String,
+) -> Result<(), String> {
+    let handle = tasks.lock().await.remove(&id);
+    let install_child = children.lock().await.remove(&id);
+
+    let backend_child = {
+        let mut backend = backend.lock().await;
+        if backend.task_id.as_deref() == Some(id.as_str()) {
+            // Suppress the supervisor before killing the child, same as
+            // `stop_backend`, so a non-zero exit code reported by the killed
+            // process (e.g. on Windows) doesn't trigger an automatic restart
+            // of a backend the user just cancelled.
+            backend.stopping = true;
+            backend.running = false;
+            backend.ready = false;
+            backend.task_id = None;
+            backend.child.take()
+        } else {
+            None
+        }
+    };
+
+    if handle.is_none() && install_child.is_none() && backend_child.is_none() {
+        return Err(format!("No task registered with id \"{}\"", id));
+    }
+
+    if let Some(handle) = handle {
+        handle.abort();
+    }
+    if let Some(child) = install_child {
+        let _ = child.kill();
+    }
+    if let Some(child) = backend_child {
+        let _ = child.kill();
+    }
+
+    let _ = app.emit("task-cancelled", &id);
+    Ok(())
 }
 
 // Start the Python backend sidecar
@@ -44,6 +316,8 @@ pub struct CLIStatus {
 async fn start_backend(
     app: tauri::AppHandle,
     state: tauri::State<'_, SharedBackendState>,
+    tasks: tauri::State<'_, TaskRegistry>,
+    id: String,
 ) -> Result<u16, String> {
     // Check if already running (short lock)
     {
@@ -56,6 +330,27 @@ async fn start_backend(
     // Find an available port
     let port = portpicker::pick_unused_port().unwrap_or(8000);
 
+    {
+        let mut backend = state.lock().await;
+        backend.stopping = false;
+        backend.restart_attempts = 0;
+    }
+
+    spawn_backend(app, state.inner().clone(), tasks.inner().clone(), id, port).await?;
+    Ok(port)
+}
+
+// Spawn the sidecar on `port`, wire up its output/termination handling, and
+// wait for it to become ready. Used both by `start_backend` and by the
+// supervisor when it restarts a crashed backend. The readiness wait runs as
+// its own task registered under `task_id` so `cancel_task` can abort it.
+async fn spawn_backend(
+    app: tauri::AppHandle,
+    state: SharedBackendState,
+    tasks: TaskRegistry,
+    task_id: String,
+    port: u16,
+) -> Result<(), String> {
     // Start the sidecar
     let sidecar = app
         .shell()
@@ -73,27 +368,68 @@ async fn start_backend(
         backend.child = Some(child);
         backend.port = port;
         backend.running = true;
+        backend.task_id = Some(task_id.clone());
     }
 
+    // Signals early termination to the readiness probe below, so we don't
+    // keep polling a port that will never come up.
+    let (terminated_tx, terminated_rx) = oneshot::channel();
+    let mut terminated_tx = Some(terminated_tx);
+
     // Spawn a task to handle sidecar output
     let app_handle = app.clone();
-    let state_clone = state.inner().clone();
+    let state_clone = state.clone();
+    let tasks_clone = tasks.clone();
+    let task_id_clone = task_id.clone();
     tauri::async_runtime::spawn(async move {
         use tauri_plugin_shell::process::CommandEvent;
         while let Some(event) = rx.recv().await {
             match event {
                 CommandEvent::Stdout(line) => {
-                    let _ = app_handle.emit("backend-log", String::from_utf8_lossy(&line).to_string());
+                    let text = String::from_utf8_lossy(&line).to_string();
+                    let (level, message) = parse_sidecar_level(&text, log::Level::Info);
+                    log::log!(target: "python-backend", level, "{}", message);
+                    let _ = app_handle.emit("backend-log", text);
                 }
                 CommandEvent::Stderr(line) => {
-                    let _ = app_handle.emit("backend-error", String::from_utf8_lossy(&line).to_string());
+                    let text = String::from_utf8_lossy(&line).to_string();
+                    let (level, message) = parse_sidecar_level(&text, log::Level::Error);
+                    log::log!(target: "python-backend", level, "{}", message);
+                    let _ = app_handle.emit("backend-error", text);
                 }
                 CommandEvent::Terminated(payload) => {
                     let _ = app_handle.emit("backend-terminated", payload.code);
                     // Update state when backend terminates
                     let mut backend = state_clone.lock().await;
                     backend.running = false;
+                    backend.ready = false;
                     backend.child = None;
+                    backend.task_id = None;
+                    let should_restart = !backend.stopping && payload.code.unwrap_or(0) != 0;
+                    let next_attempt = backend.restart_attempts + 1;
+                    drop(backend);
+
+                    if let Some(tx) = terminated_tx.take() {
+                        let _ = tx.send(());
+                    }
+
+                    if should_restart && next_attempt <= MAX_RESTART_ATTEMPTS {
+                        let app_for_restart = app_handle.clone();
+                        let state_for_restart = state_clone.clone();
+                        let tasks_for_restart = tasks_clone.clone();
+                        let task_id_for_restart = task_id_clone.clone();
+                        tauri::async_runtime::spawn(async move {
+                            restart_backend_with_backoff(
+                                app_for_restart,
+                                state_for_restart,
+                                tasks_for_restart,
+                                task_id_for_restart,
+                                port,
+                                next_attempt,
+                            )
+                            .await;
+                        });
+                    }
                     break;
                 }
                 _ => {}
@@ -101,10 +437,107 @@ async fn start_backend(
         }
     });
 
-    // Wait a bit for the backend to start
-    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+    // Wait for the sidecar to actually accept connections instead of
+    // assuming it's up after a fixed delay. This runs as its own task so a
+    // `cancel_task` call can abort it mid-probe.
+    let (ready_tx, ready_rx) = oneshot::channel();
+    let tasks_for_wait = tasks.clone();
+    let task_id_for_wait = task_id.clone();
+    let wait_handle = tauri::async_runtime::spawn(async move {
+        let result = tokio::select! {
+            result = wait_for_backend_ready(port, READINESS_TIMEOUT) => result,
+            _ = terminated_rx => Err("Backend process terminated before it became ready".to_string()),
+        };
+        tasks_for_wait.lock().await.remove(&task_id_for_wait);
+        let _ = ready_tx.send(result);
+    });
+    tasks.lock().await.insert(task_id, wait_handle);
 
-    Ok(port)
+    match ready_rx.await {
+        Ok(Ok(())) => {
+            let mut backend = state.lock().await;
+            backend.ready = true;
+            backend.restart_attempts = 0;
+            Ok(())
+        }
+        Ok(Err(e)) => Err(e),
+        Err(_) => Err("Backend readiness check was cancelled".to_string()),
+    }
+}
+
+// Wait out an exponential backoff, emit `backend-restarting`, then re-spawn
+// the sidecar on the same port. A deliberate `stop_backend` call sets
+// `stopping` and is checked right before re-spawning so it wins a race with
+// an in-flight backoff.
+async fn restart_backend_with_backoff(
+    app: tauri::AppHandle,
+    state: SharedBackendState,
+    tasks: TaskRegistry,
+    task_id: String,
+    port: u16,
+    attempt: u32,
+) {
+    {
+        let mut backend = state.lock().await;
+        backend.restart_attempts = attempt;
+    }
+
+    let _ = app.emit(
+        "backend-restarting",
+        BackendRestartingPayload {
+            attempt,
+            max_attempts: MAX_RESTART_ATTEMPTS,
+        },
+    );
+
+    tokio::time::sleep(restart_backoff(attempt)).await;
+
+    {
+        let backend = state.lock().await;
+        if backend.stopping {
+            return;
+        }
+    }
+
+    if let Err(e) = spawn_backend(app.clone(), state, tasks, task_id, port).await {
+        let _ = app.emit("backend-error", format!("Restart attempt {} failed: {}", attempt, e));
+    }
+}
+
+// Poll `127.0.0.1:port` until it accepts a TCP connection or `timeout` elapses,
+// using a capped exponential backoff between attempts.
+async fn wait_for_backend_ready(port: u16, timeout: Duration) -> Result<(), String> {
+    let addr: SocketAddr = ([127, 0, 0, 1], port).into();
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut delay = READINESS_POLL_MIN;
+
+    loop {
+        if TcpStream::connect(addr).await.is_ok() {
+            return Ok(());
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            return Err(format!(
+                "Backend did not become ready on port {} within {:?}",
+                port, timeout
+            ));
+        }
+
+        tokio::time::sleep(delay).await;
+        delay = next_readiness_delay(delay);
+    }
+}
+
+// Backoff before the supervisor's `attempt`-th restart (1-indexed): doubles
+// from `RESTART_BACKOFF_BASE`, capped at `RESTART_BACKOFF_MAX`.
+fn restart_backoff(attempt: u32) -> Duration {
+    std::cmp::min(RESTART_BACKOFF_BASE * 2u32.pow(attempt - 1), RESTART_BACKOFF_MAX)
+}
+
+// Next polling interval after a failed readiness probe: doubles `current`,
+// capped at `READINESS_POLL_MAX`.
+fn next_readiness_delay(current: Duration) -> Duration {
+    std::cmp::min(current * 2, READINESS_POLL_MAX)
 }
 
 // Stop the Python backend
@@ -112,11 +545,17 @@ async fn start_backend(
 async fn stop_backend(state: tauri::State<'_, SharedBackendState>) -> Result<(), String> {
     let mut backend = state.lock().await;
 
+    // Suppress the supervisor before killing the child, so it doesn't race
+    // a crash-restart against this deliberate shutdown.
+    backend.stopping = true;
+
     if let Some(child) = backend.child.take() {
         child.kill().map_err(|e| format!("Failed to kill backend: {}", e))?;
     }
+    backend.task_id = None;
 
     backend.running = false;
+    backend.ready = false;
     Ok(())
 }
 
@@ -126,6 +565,7 @@ async fn get_backend_status(state: tauri::State<'_, SharedBackendState>) -> Resu
     let backend = state.lock().await;
     Ok(BackendStatus {
         running: backend.running,
+        ready: backend.ready,
         port: backend.port,
     })
 }
@@ -137,82 +577,195 @@ async fn get_backend_port(state: tauri::State<'_, SharedBackendState>) -> Result
     Ok(backend.port)
 }
 
+// Resolve `name` to an absolute path the same way on macOS, Linux, and
+// Windows (including `.cmd`/`.exe` shims for npm-installed binaries),
+// instead of shelling out to the Unix `which` binary.
+fn locate_tool(name: &str) -> Option<PathBuf> {
+    which::which(name).ok()
+}
+
+// Run `path --version` and return its trimmed stdout, or `None` if the tool
+// couldn't be invoked or exited non-zero.
+fn tool_version(path: &PathBuf) -> Option<String> {
+    std::process::Command::new(path)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+}
+
 // Check Claude Code CLI status
 #[tauri::command]
 async fn check_claude_cli() -> Result<CLIStatus, String> {
-    use std::process::Command;
+    // `tool_version` shells out and `locate_tool` searches PATH on disk, so
+    // both block; run the six lookups off the async runtime instead of
+    // stalling a Tokio worker thread, same as `check_claude_cli_update`.
+    tokio::task::spawn_blocking(|| {
+        let claude_path = locate_tool("claude");
+        let installed = claude_path.is_some();
+        let version = claude_path.as_ref().and_then(tool_version);
+
+        let node_path = locate_tool("node");
+        let node_version = node_path.as_ref().and_then(tool_version);
 
-    // Check if claude is installed
-    let claude_check = Command::new("which")
-        .arg("claude")
-        .output();
+        let npm_path = locate_tool("npm");
+        let npm_version = npm_path.as_ref().and_then(tool_version);
 
-    let (installed, path) = match claude_check {
-        Ok(output) if output.status.success() => {
-            let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
-            (true, Some(path))
+        CLIStatus {
+            installed,
+            path: claude_path.map(|p| p.display().to_string()),
+            version,
+            node_path: node_path.map(|p| p.display().to_string()),
+            node_version,
+            npm_path: npm_path.map(|p| p.display().to_string()),
+            npm_version,
         }
-        _ => (false, None),
-    };
+    })
+    .await
+    .map_err(|e| format!("CLI detection task panicked: {}", e))
+}
 
-    // Get version if installed
-    let version = if installed {
-        Command::new("claude")
-            .arg("--version")
-            .output()
-            .ok()
-            .and_then(|o| {
-                if o.status.success() {
-                    Some(String::from_utf8_lossy(&o.stdout).trim().to_string())
-                } else {
-                    None
+// Run `npm` with `args`, streaming its output as `cli-install-progress`
+// events and finishing with `cli-install-complete`. Shared by
+// `install_claude_cli` and `update_claude_cli` so both get identical
+// progress reporting. Registers the task and child process under `id` so
+// `cancel_task` can abort it mid-install.
+async fn run_npm_with_progress(
+    app: tauri::AppHandle,
+    tasks: TaskRegistry,
+    children: ChildRegistry,
+    id: String,
+    args: &[&str],
+) -> Result<(), String> {
+    let (mut rx, child) = app
+        .shell()
+        .command("npm")
+        .args(args)
+        .spawn()
+        .map_err(|e| format!("Failed to start npm: {}", e))?;
+
+    children.lock().await.insert(id.clone(), child);
+
+    let tasks_for_task = tasks.clone();
+    let children_for_task = children.clone();
+    let id_for_task = id.clone();
+    let handle = tauri::async_runtime::spawn(async move {
+        use tauri_plugin_shell::process::CommandEvent;
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(line) | CommandEvent::Stderr(line) => {
+                    let _ = app.emit("cli-install-progress", String::from_utf8_lossy(&line).to_string());
                 }
-            })
-    } else {
-        None
-    };
+                CommandEvent::Terminated(payload) => {
+                    let _ = app.emit("cli-install-complete", payload.code);
+                    tasks_for_task.lock().await.remove(&id_for_task);
+                    children_for_task.lock().await.remove(&id_for_task);
+                    break;
+                }
+                _ => {}
+            }
+        }
+    });
 
-    // Check Node.js
-    let node_installed = Command::new("which")
-        .arg("node")
-        .output()
-        .map(|o| o.status.success())
-        .unwrap_or(false);
+    tasks.lock().await.insert(id, handle);
+    Ok(())
+}
 
-    // Check npm
-    let npm_installed = Command::new("which")
-        .arg("npm")
-        .output()
-        .map(|o| o.status.success())
-        .unwrap_or(false);
-
-    Ok(CLIStatus {
-        installed,
-        path,
-        version,
-        node_installed,
-        npm_installed,
-    })
+// Install Claude Code CLI, streaming progress instead of blocking until npm
+// finishes. Resolves as soon as the install has started; the frontend should
+// watch `cli-install-progress` and `cli-install-complete`, and can cancel it
+// via `cancel_task(id)`.
+#[tauri::command]
+async fn install_claude_cli(
+    app: tauri::AppHandle,
+    tasks: tauri::State<'_, TaskRegistry>,
+    children: tauri::State<'_, ChildRegistry>,
+    id: String,
+) -> Result<String, String> {
+    run_npm_with_progress(
+        app,
+        tasks.inner().clone(),
+        children.inner().clone(),
+        id,
+        &["install", "-g", "@anthropic-ai/claude-code"],
+    )
+    .await?;
+    Ok("Claude Code CLI installation started".to_string())
 }
 
-// Install Claude Code CLI
+#[derive(Serialize, Deserialize)]
+pub struct CLIUpdateStatus {
+    current: Option<String>,
+    latest: Option<String>,
+    update_available: bool,
+}
+
+// A CLI version string often carries extra words (e.g. "1.2.3 (Claude Code)");
+// pull out the first token that parses as semver.
+fn parse_semver(raw: &str) -> Option<semver::Version> {
+    raw.split_whitespace()
+        .find_map(|tok| semver::Version::parse(tok.trim_start_matches('v')).ok())
+}
+
+// Compare the installed Claude Code CLI version against the latest published
+// on npm.
 #[tauri::command]
-async fn install_claude_cli() -> Result<String, String> {
-    use std::process::Command;
+async fn check_claude_cli_update() -> Result<CLIUpdateStatus, String> {
+    // `locate_tool` (a PATH search on disk), `tool_version` and the `npm view`
+    // round-trip (both subprocesses) all block, so run them off the async runtime rather than stalling
+    // a Tokio worker thread for the duration of a registry lookup.
+    tokio::task::spawn_blocking(|| {
+        let current = locate_tool("claude").as_ref().and_then(tool_version);
 
-    let output = Command::new("npm")
-        .args(["install", "-g", "@anthropic-ai/claude-code"])
-        .output()
-        .map_err(|e| format!("Failed to run npm: {}", e))?;
-
-    if output.status.success() {
-        Ok("Claude Code CLI installed successfully".to_string())
-    } else {
-        Err(format!(
-            "Installation failed: {}",
-            String::from_utf8_lossy(&output.stderr)
-        ))
-    }
+        let output = std::process::Command::new("npm")
+            .args(["view", "@anthropic-ai/claude-code", "version"])
+            .output()
+            .map_err(|e| format!("Failed to query npm registry: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "npm view failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        let latest = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+        let update_available = match (current.as_deref().and_then(parse_semver), parse_semver(&latest)) {
+            (Some(current_version), Some(latest_version)) => latest_version > current_version,
+            _ => false,
+        };
+
+        Ok(CLIUpdateStatus {
+            current,
+            latest: Some(latest),
+            update_available,
+        })
+    })
+    .await
+    .map_err(|e| format!("Update check task panicked: {}", e))?
+}
+
+// Update Claude Code CLI to the latest published version, streaming progress
+// through the same events as `install_claude_cli`, and cancellable the same
+// way via `cancel_task(id)`.
+#[tauri::command]
+async fn update_claude_cli(
+    app: tauri::AppHandle,
+    tasks: tauri::State<'_, TaskRegistry>,
+    children: tauri::State<'_, ChildRegistry>,
+    id: String,
+) -> Result<String, String> {
+    run_npm_with_progress(
+        app,
+        tasks.inner().clone(),
+        children.inner().clone(),
+        id,
+        &["install", "-g", "@anthropic-ai/claude-code@latest"],
+    )
+    .await?;
+    Ok("Claude Code CLI update started".to_string())
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -224,6 +777,8 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_notification::init())
         .manage(Arc::new(Mutex::new(BackendState::default())))
+        .manage(Arc::new(Mutex::new(HashMap::new())) as TaskRegistry)
+        .manage(Arc::new(Mutex::new(HashMap::new())) as ChildRegistry)
         .invoke_handler(tauri::generate_handler![
             start_backend,
             stop_backend,
@@ -231,8 +786,15 @@ pub fn run() {
             get_backend_port,
             check_claude_cli,
             install_claude_cli,
+            check_claude_cli_update,
+            update_claude_cli,
+            get_log_path,
+            set_log_level,
+            cancel_task,
         ])
         .setup(|app| {
+            init_logging(app.handle())?;
+
             // Auto-start backend on app launch
             let app_handle = app.handle().clone();
             tauri::async_runtime::spawn(async move {
@@ -252,3 +814,88 @@ pub fn run() {
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sidecar_level_recognizes_python_logging_prefixes() {
+        assert_eq!(
+            parse_sidecar_level("INFO: listening on :8000", log::Level::Info),
+            (log::Level::Info, "listening on :8000")
+        );
+        assert_eq!(
+            parse_sidecar_level("ERROR: traceback", log::Level::Info),
+            (log::Level::Error, "traceback")
+        );
+        assert_eq!(
+            parse_sidecar_level("WARNING: deprecated flag", log::Level::Error),
+            (log::Level::Warn, "deprecated flag")
+        );
+        assert_eq!(
+            parse_sidecar_level("CRITICAL: out of memory", log::Level::Info),
+            (log::Level::Error, "out of memory")
+        );
+        assert_eq!(
+            parse_sidecar_level("DEBUG: cache hit", log::Level::Error),
+            (log::Level::Debug, "cache hit")
+        );
+    }
+
+    #[test]
+    fn parse_sidecar_level_falls_back_to_default_without_a_prefix() {
+        assert_eq!(
+            parse_sidecar_level("plain line, no prefix", log::Level::Info),
+            (log::Level::Info, "plain line, no prefix")
+        );
+        assert_eq!(
+            parse_sidecar_level("plain line, no prefix", log::Level::Error),
+            (log::Level::Error, "plain line, no prefix")
+        );
+    }
+
+    #[test]
+    fn parse_semver_extracts_the_first_valid_version_token() {
+        assert_eq!(
+            parse_semver("1.2.3"),
+            Some(semver::Version::new(1, 2, 3))
+        );
+        assert_eq!(
+            parse_semver("1.2.3 (Claude Code)"),
+            Some(semver::Version::new(1, 2, 3))
+        );
+        assert_eq!(
+            parse_semver("v1.2.3"),
+            Some(semver::Version::new(1, 2, 3))
+        );
+    }
+
+    #[test]
+    fn parse_semver_returns_none_for_unparsable_input() {
+        assert_eq!(parse_semver(""), None);
+        assert_eq!(parse_semver("not a version"), None);
+    }
+
+    #[test]
+    fn restart_backoff_doubles_then_caps() {
+        assert_eq!(restart_backoff(1), Duration::from_secs(1));
+        assert_eq!(restart_backoff(2), Duration::from_secs(2));
+        assert_eq!(restart_backoff(3), Duration::from_secs(4));
+        assert_eq!(restart_backoff(4), Duration::from_secs(8));
+        assert_eq!(restart_backoff(5), Duration::from_secs(16));
+        assert_eq!(restart_backoff(6), RESTART_BACKOFF_MAX);
+        assert_eq!(restart_backoff(10), RESTART_BACKOFF_MAX);
+    }
+
+    #[test]
+    fn next_readiness_delay_doubles_then_caps() {
+        assert_eq!(next_readiness_delay(READINESS_POLL_MIN), Duration::from_millis(200));
+        assert_eq!(next_readiness_delay(Duration::from_secs(1)), Duration::from_secs(2));
+        assert_eq!(next_readiness_delay(READINESS_POLL_MAX), READINESS_POLL_MAX);
+        assert_eq!(
+            next_readiness_delay(Duration::from_secs(10)),
+            READINESS_POLL_MAX
+        );
+    }
+}